@@ -40,6 +40,8 @@ pub enum Command {
     Check(CheckArgs),
     /// Initialize a new .fence.toml config.
     Init(InitArgs),
+    /// Resolve violations from the last check automatically.
+    Fix(FixArgs),
 }
 
 /// Arguments for the check command.
@@ -48,6 +50,42 @@ pub struct CheckArgs {
     /// Paths to check (files or directories).
     #[arg(value_name = "PATH", allow_hyphen_values = true)]
     pub paths: Vec<PathBuf>,
+
+    /// Output format for results.
+    #[arg(long = "format", value_enum, default_value_t = OutputFormat::Json)]
+    pub format: OutputFormat,
+
+    /// Only check files that differ from `--since` in the working directory
+    /// (or in the index, with `--staged`).
+    #[arg(long = "changed")]
+    pub changed: bool,
+
+    /// Git revision to diff against when `--changed` is set.
+    #[arg(long = "since", default_value = "HEAD")]
+    pub since: String,
+
+    /// With `--changed`, diff the index instead of the working directory.
+    #[arg(long = "staged", requires = "changed")]
+    pub staged: bool,
+
+    /// Ratchet against a baseline snapshot: only fail on violations that
+    /// grow past both their limit and their recorded baseline size.
+    #[arg(long = "baseline", value_name = "FILE", conflicts_with = "write_baseline")]
+    pub baseline: Option<PathBuf>,
+
+    /// Regenerate the baseline snapshot at FILE from the current check,
+    /// instead of reporting violations.
+    #[arg(long = "write-baseline", value_name = "FILE")]
+    pub write_baseline: Option<PathBuf>,
+}
+
+/// Output format for `fence check`.
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Fence's own JSON shape.
+    Json,
+    /// SARIF 2.1.0, for code-scanning and editor integration.
+    Sarif,
 }
 
 /// Arguments for the init command.
@@ -57,3 +95,24 @@ pub struct InitArgs {
     #[arg(long = "baseline")]
     pub baseline: bool,
 }
+
+/// Arguments for the fix command.
+#[derive(Args, Debug, Clone)]
+pub struct FixArgs {
+    /// Paths to check (files or directories).
+    #[arg(value_name = "PATH", allow_hyphen_values = true)]
+    pub paths: Vec<PathBuf>,
+
+    /// Resolve violations by merging glob exemptions into `.fence.toml`.
+    #[arg(long = "add-exemptions", conflicts_with = "insert_pragmas")]
+    pub add_exemptions: bool,
+
+    /// Resolve violations by inserting an inline ignore pragma at the top
+    /// of each offending file.
+    #[arg(long = "insert-pragmas")]
+    pub insert_pragmas: bool,
+
+    /// Print the unified diff of intended edits without touching disk.
+    #[arg(long = "dry-run")]
+    pub dry_run: bool,
+}
@@ -0,0 +1,219 @@
+//! Baseline ratchet: fail only on new violations relative to a stored
+//! snapshot.
+//!
+//! Unlike `fence init --baseline`, which bakes current violations into
+//! config as permanent exemptions, a ratchet snapshot tolerates a file's
+//! *current* size but still fails the check once that file grows past it.
+
+use std::collections::{BTreeMap, HashMap};
+use std::io;
+use std::path::Path;
+
+use loq_core::report::{FindingKind, Report};
+use loq_core::MatchBy;
+
+use crate::output::json::JsonViolation;
+
+/// A loaded baseline: path -> recorded violation at snapshot time.
+pub struct Baseline {
+    entries: HashMap<String, JsonViolation>,
+}
+
+impl Baseline {
+    /// Load a baseline snapshot written by [`write`].
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let raw = std::fs::read_to_string(path)?;
+        let violations: BTreeMap<String, JsonViolation> = serde_json::from_str(&raw)?;
+        Ok(Self {
+            entries: violations.into_iter().collect(),
+        })
+    }
+
+    /// Whether a violation at `path` with `actual` lines should still be
+    /// reported given this baseline: true when the file is new, or has grown
+    /// past its recorded baseline line count.
+    fn exceeds(&self, path: &str, actual: usize) -> bool {
+        match self.entries.get(path) {
+            Some(recorded) => actual > recorded.lines,
+            None => true,
+        }
+    }
+}
+
+/// Drop violations that don't exceed the baseline, demoting them back to
+/// `Ok`. Non-violation findings are left untouched.
+pub fn apply(report: &mut Report, baseline: &Baseline) {
+    for finding in &mut report.findings {
+        let FindingKind::Violation { actual, limit, .. } = &finding.kind else {
+            continue;
+        };
+
+        if !baseline.exceeds(&finding.path, *actual) {
+            report.summary.errors = report.summary.errors.saturating_sub(1);
+            finding.kind = FindingKind::Ok {
+                actual: *actual,
+                limit: *limit,
+            };
+        }
+    }
+}
+
+/// Regenerate a baseline snapshot from the current findings, as a stable
+/// map of path -> violation so the committed file diffs cleanly.
+pub fn write<W: io::Write>(writer: &mut W, report: &Report) -> io::Result<()> {
+    let violations: BTreeMap<String, JsonViolation> = report
+        .findings
+        .iter()
+        .filter_map(|finding| {
+            if let FindingKind::Violation {
+                actual,
+                limit,
+                matched_by,
+                ..
+            } = &finding.kind
+            {
+                let rule = match matched_by {
+                    MatchBy::Rule { pattern } => pattern.clone(),
+                    MatchBy::Default => "default".to_string(),
+                };
+                Some((
+                    finding.path.clone(),
+                    JsonViolation {
+                        path: finding.path.clone(),
+                        lines: *actual,
+                        max_lines: *limit,
+                        rule,
+                    },
+                ))
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    serde_json::to_writer_pretty(&mut *writer, &violations)?;
+    writeln!(writer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use loq_core::report::{Finding, Summary};
+
+    fn violation(path: &str, actual: usize, limit: usize) -> Finding {
+        Finding {
+            path: path.to_string(),
+            kind: FindingKind::Violation {
+                actual,
+                limit,
+                matched_by: MatchBy::Default,
+            },
+        }
+    }
+
+    fn baseline_from(report: &Report) -> Baseline {
+        let path = std::env::temp_dir().join(format!("fence-baseline-test-{}", std::process::id()));
+        let mut buf = Vec::new();
+        write(&mut buf, report).unwrap();
+        std::fs::write(&path, buf).unwrap();
+        let baseline = Baseline::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        baseline
+    }
+
+    #[test]
+    fn demotes_violations_at_or_below_baseline() {
+        let snapshot_report = Report {
+            findings: vec![violation("big.rs", 400, 300)],
+            summary: Summary {
+                total: 1,
+                errors: 1,
+            },
+        };
+        let baseline = baseline_from(&snapshot_report);
+
+        let mut report = Report {
+            findings: vec![violation("big.rs", 400, 300)],
+            summary: Summary {
+                total: 1,
+                errors: 1,
+            },
+        };
+        apply(&mut report, &baseline);
+
+        assert!(matches!(report.findings[0].kind, FindingKind::Ok { .. }));
+        assert_eq!(report.summary.errors, 0);
+    }
+
+    #[test]
+    fn still_fails_when_file_grows_past_baseline() {
+        let snapshot_report = Report {
+            findings: vec![violation("big.rs", 400, 300)],
+            summary: Summary {
+                total: 1,
+                errors: 1,
+            },
+        };
+        let baseline = baseline_from(&snapshot_report);
+
+        let mut report = Report {
+            findings: vec![violation("big.rs", 450, 300)],
+            summary: Summary {
+                total: 1,
+                errors: 1,
+            },
+        };
+        apply(&mut report, &baseline);
+
+        assert!(matches!(
+            report.findings[0].kind,
+            FindingKind::Violation { .. }
+        ));
+        assert_eq!(report.summary.errors, 1);
+    }
+
+    #[test]
+    fn fails_on_brand_new_oversized_file_not_in_baseline() {
+        let snapshot_report = Report {
+            findings: vec![],
+            summary: Summary {
+                total: 0,
+                errors: 0,
+            },
+        };
+        let baseline = baseline_from(&snapshot_report);
+
+        let mut report = Report {
+            findings: vec![violation("new.rs", 400, 300)],
+            summary: Summary {
+                total: 1,
+                errors: 1,
+            },
+        };
+        apply(&mut report, &baseline);
+
+        assert!(matches!(
+            report.findings[0].kind,
+            FindingKind::Violation { .. }
+        ));
+        assert_eq!(report.summary.errors, 1);
+    }
+
+    #[test]
+    fn write_serializes_as_a_path_keyed_map() {
+        let report = Report {
+            findings: vec![violation("z.rs", 400, 300), violation("a.rs", 500, 300)],
+            summary: Summary {
+                total: 2,
+                errors: 2,
+            },
+        };
+
+        let mut buf = Vec::new();
+        write(&mut buf, &report).unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&buf).unwrap();
+
+        let keys: Vec<&str> = json.as_object().unwrap().keys().map(String::as_str).collect();
+        assert_eq!(keys, vec!["a.rs", "z.rs"]);
+    }
+}
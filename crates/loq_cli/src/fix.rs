@@ -0,0 +1,282 @@
+//! `fence fix`: auto-resolve violations from a check.
+//!
+//! Each `FindingKind::Violation` is resolved by one of two strategies
+//! (`--add-exemptions` merges glob rules into `.fence.toml`,
+//! `--insert-pragmas` writes the inline ignore comment from the suppression
+//! feature), then the tree is re-checked so we can report how many
+//! violations were actually resolved versus left.
+
+use std::io;
+use std::path::Path;
+
+use loq_core::report::{FindingKind, Report};
+use regex::Regex;
+use similar::TextDiff;
+use toml_edit::{value, Array, Document};
+
+use crate::suppress;
+
+/// How many violations a fix pass resolved, versus how many remain after
+/// re-checking the tree.
+pub struct FixSummary {
+    pub resolved: usize,
+    pub remaining: usize,
+}
+
+fn violation_count(report: &Report) -> usize {
+    report
+        .findings
+        .iter()
+        .filter(|finding| matches!(finding.kind, FindingKind::Violation { .. }))
+        .count()
+}
+
+/// Compare a report from before a fix pass to one from re-checking
+/// afterward.
+pub fn summarize(before: &Report, after: &Report) -> FixSummary {
+    let before_count = violation_count(before);
+    let after_count = violation_count(after);
+    FixSummary {
+        resolved: before_count.saturating_sub(after_count),
+        remaining: after_count,
+    }
+}
+
+/// A unified diff of one edit `fix` would make, for `--dry-run`.
+fn unified_diff(path: &Path, original: &str, updated: &str) -> String {
+    let label = path.display().to_string();
+    TextDiff::from_lines(original, updated)
+        .unified_diff()
+        .header(&format!("a/{label}"), &format!("b/{label}"))
+        .to_string()
+}
+
+/// The line-comment token for a file with this path, based on its
+/// extension. `None` for extensions fence doesn't know the comment syntax
+/// of, so callers can skip or error rather than write an invalid comment.
+fn comment_token(path: &Path) -> Option<&'static str> {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("rs" | "c" | "h" | "cpp" | "hpp" | "java" | "js" | "ts" | "go" | "swift" | "kt") => {
+            Some("//")
+        }
+        Some("py" | "rb" | "sh" | "bash" | "zsh" | "toml" | "yaml" | "yml") => Some("#"),
+        _ => None,
+    }
+}
+
+/// Prepend an ignore pragma to `path` (after a leading shebang, if any).
+/// A no-op if `path` is already suppressed by `ignore_pattern`, or an error
+/// if fence doesn't know the file's comment syntax. In dry-run mode,
+/// returns the unified diff of the intended edit instead of writing it.
+pub fn insert_pragma(
+    path: &Path,
+    ignore_pattern: &Regex,
+    dry_run: bool,
+) -> io::Result<Option<String>> {
+    let token = comment_token(path).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("no known comment syntax for {}", path.display()),
+        )
+    })?;
+
+    let original = std::fs::read_to_string(path)?;
+    if suppress::is_suppressed(&original, ignore_pattern) {
+        return Ok(None);
+    }
+
+    let pragma = format!("{token} fence:ignore-file\n");
+    if !ignore_pattern.is_match(&pragma) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "generated pragma `{}` doesn't match the configured ignore_pattern `{}`; \
+                 use a pragma-compatible pattern or suppress {} another way",
+                pragma.trim_end(),
+                ignore_pattern.as_str(),
+                path.display()
+            ),
+        ));
+    }
+
+    let insert_at = if original.starts_with("#!") {
+        original.find('\n').map_or(original.len(), |idx| idx + 1)
+    } else {
+        0
+    };
+
+    let mut updated = original.clone();
+    updated.insert_str(insert_at, &pragma);
+
+    if dry_run {
+        return Ok(Some(unified_diff(path, &original, &updated)));
+    }
+
+    std::fs::write(path, &updated)?;
+    Ok(None)
+}
+
+/// Merge `globs` into the `exemptions` array of the `.fence.toml` at
+/// `config_path`, preserving existing structure and comments. In dry-run
+/// mode, returns the unified diff of the intended edit instead of writing
+/// it.
+pub fn add_exemptions(
+    config_path: &Path,
+    globs: &[String],
+    dry_run: bool,
+) -> io::Result<Option<String>> {
+    let original = std::fs::read_to_string(config_path)?;
+    let mut doc = original
+        .parse::<Document>()
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+    let exemptions = doc["exemptions"]
+        .or_insert(value(Array::new()))
+        .as_array_mut()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "exemptions is not an array"))?;
+
+    let existing: Vec<String> = exemptions
+        .iter()
+        .filter_map(|item| item.as_str().map(str::to_string))
+        .collect();
+
+    for glob in globs {
+        if !existing.contains(glob) {
+            exemptions.push(glob.as_str());
+        }
+    }
+
+    let updated = doc.to_string();
+    if updated == original {
+        return Ok(None);
+    }
+
+    if dry_run {
+        return Ok(Some(unified_diff(config_path, &original, &updated)));
+    }
+
+    std::fs::write(config_path, &updated)?;
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use loq_core::config::DEFAULT_IGNORE_PATTERN;
+
+    fn scratch_file(name: &str, contents: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("fence-fix-test-{}-{name}", std::process::id()));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn comment_token_known_extensions() {
+        assert_eq!(comment_token(Path::new("main.rs")), Some("//"));
+        assert_eq!(comment_token(Path::new("script.py")), Some("#"));
+    }
+
+    #[test]
+    fn comment_token_unknown_extension_is_none() {
+        assert_eq!(comment_token(Path::new("styles.css")), None);
+        assert_eq!(comment_token(Path::new("query.sql")), None);
+    }
+
+    #[test]
+    fn insert_pragma_errors_on_unknown_extension() {
+        let path = scratch_file("unknown-ext.css", "body {}\n");
+        let pattern = Regex::new(DEFAULT_IGNORE_PATTERN).unwrap();
+
+        let result = insert_pragma(&path, &pattern, true);
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn insert_pragma_inserts_after_shebang() {
+        let path = scratch_file("shebang.py", "#!/usr/bin/env python3\nprint(1)\n");
+        let pattern = Regex::new(DEFAULT_IGNORE_PATTERN).unwrap();
+
+        insert_pragma(&path, &pattern, false).unwrap();
+        let updated = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let mut lines = updated.lines();
+        assert_eq!(lines.next(), Some("#!/usr/bin/env python3"));
+        assert_eq!(lines.next(), Some("# fence:ignore-file"));
+    }
+
+    #[test]
+    fn insert_pragma_is_idempotent() {
+        let path = scratch_file("already-tagged.rs", "// fence:ignore-file\nfn main() {}\n");
+        let pattern = Regex::new(DEFAULT_IGNORE_PATTERN).unwrap();
+
+        let result = insert_pragma(&path, &pattern, false).unwrap();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(result.is_none());
+        assert_eq!(contents, "// fence:ignore-file\nfn main() {}\n");
+    }
+
+    #[test]
+    fn insert_pragma_dry_run_does_not_write() {
+        let path = scratch_file("dry-run.rs", "fn main() {}\n");
+        let pattern = Regex::new(DEFAULT_IGNORE_PATTERN).unwrap();
+
+        let diff = insert_pragma(&path, &pattern, true).unwrap();
+        let untouched = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(diff.unwrap().contains("fence:ignore-file"));
+        assert_eq!(untouched, "fn main() {}\n");
+    }
+
+    #[test]
+    fn insert_pragma_errors_when_pragma_does_not_match_custom_pattern() {
+        // Regression test: a custom `ignore_pattern` that doesn't match the
+        // literal fence writes must not silently produce a pragma the
+        // suppression scan (and thus the re-check) will never recognize.
+        let path = scratch_file("custom-pattern.rs", "fn main() {}\n");
+        let pattern = Regex::new(r"do-not-lint").unwrap();
+
+        let result = insert_pragma(&path, &pattern, true);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn add_exemptions_dedups_existing_globs() {
+        let path = scratch_file("fence.toml", "exemptions = [\"vendor/**\"]\n");
+
+        let result = add_exemptions(
+            &path,
+            &["vendor/**".to_string(), "generated/**".to_string()],
+            false,
+        )
+        .unwrap();
+        let updated = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(result.is_none());
+        assert_eq!(updated.matches("vendor/**").count(), 1);
+        assert!(updated.contains("generated/**"));
+    }
+
+    #[test]
+    fn add_exemptions_preserves_comments() {
+        let path = scratch_file(
+            "fence-with-comment.toml",
+            "# top-level config\ndefault_limit = 300\n",
+        );
+
+        add_exemptions(&path, &["vendor/**".to_string()], false).unwrap();
+        let updated = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(updated.contains("# top-level config"));
+        assert!(updated.contains("vendor/**"));
+    }
+}
@@ -0,0 +1,144 @@
+//! Git-aware file selection for `fence check --changed`.
+//!
+//! Restricts a check to files that differ from a given revision, so large
+//! repos can gate only what a PR touched instead of re-scanning everything.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use git2::{DiffOptions, Repository};
+
+/// Resolve the set of paths that differ from `since` in the repository that
+/// contains `start`.
+///
+/// When `staged` is `true`, the index is diffed against `since` instead of
+/// the working directory, and only changes already staged are considered —
+/// a new file must be `git add`ed first. Otherwise, untracked files are
+/// always included, since a brand-new file is, by definition, changed.
+pub fn changed_files(start: &Path, since: &str, staged: bool) -> Result<HashSet<PathBuf>, git2::Error> {
+    let repo = Repository::discover(start)?;
+    let workdir = repo.workdir().unwrap_or_else(|| repo.path());
+
+    let tree = repo.revparse_single(since)?.peel_to_tree()?;
+
+    let mut opts = DiffOptions::new();
+    opts.include_untracked(true).recurse_untracked_dirs(true);
+
+    let diff = if staged {
+        repo.diff_tree_to_index(Some(&tree), None, Some(&mut opts))?
+    } else {
+        repo.diff_tree_to_workdir_with_index(Some(&tree), Some(&mut opts))?
+    };
+
+    let mut changed = HashSet::new();
+    for delta in diff.deltas() {
+        if let Some(path) = delta.new_file().path().or_else(|| delta.old_file().path()) {
+            let absolute = workdir.join(path);
+            changed.insert(absolute.canonicalize().unwrap_or(absolute));
+        }
+    }
+
+    Ok(changed)
+}
+
+/// Intersect `paths` with `changed`, keeping only files that are part of the
+/// changed set. `changed` only ever holds file paths under `workdir`, so
+/// this must be called on the individual files fence's walker has already
+/// resolved, not on raw directory arguments — a directory will never match
+/// and would otherwise be silently dropped, so it's kept unfiltered instead.
+/// Paths outside `workdir` are also passed through untouched, since git
+/// can't tell us anything about them.
+pub fn filter_changed(paths: Vec<PathBuf>, workdir: &Path, changed: &HashSet<PathBuf>) -> Vec<PathBuf> {
+    paths
+        .into_iter()
+        .filter(|path| {
+            if path.is_dir() {
+                return true;
+            }
+            let Ok(canonical) = path.canonicalize() else {
+                return true;
+            };
+            if !canonical.starts_with(workdir) {
+                return true;
+            }
+            changed.contains(&canonical)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A scratch directory under the OS temp dir, removed on drop.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let path = std::env::temp_dir().join(format!("fence-git-diff-test-{name}-{}", std::process::id()));
+            let _ = std::fs::remove_dir_all(&path);
+            std::fs::create_dir_all(&path).unwrap();
+            Self(path.canonicalize().unwrap())
+        }
+
+        fn path(&self) -> &Path {
+            &self.0
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn keeps_directory_arguments_unfiltered() {
+        let workdir = TempDir::new("keeps-dirs");
+        let subdir = workdir.path().join("src");
+        std::fs::create_dir_all(&subdir).unwrap();
+
+        let changed = HashSet::new();
+        let kept = filter_changed(vec![subdir.clone()], workdir.path(), &changed);
+
+        assert_eq!(kept, vec![subdir]);
+    }
+
+    #[test]
+    fn keeps_paths_outside_workdir() {
+        let workdir = TempDir::new("keeps-outside-workdir");
+        let outside = TempDir::new("outside");
+        let file = outside.path().join("vendor.rs");
+        std::fs::write(&file, "fn main() {}").unwrap();
+
+        let changed = HashSet::new();
+        let kept = filter_changed(vec![file.clone()], workdir.path(), &changed);
+
+        assert_eq!(kept, vec![file]);
+    }
+
+    #[test]
+    fn drops_unchanged_files_inside_workdir() {
+        let workdir = TempDir::new("drops-unchanged");
+        let file = workdir.path().join("main.rs");
+        std::fs::write(&file, "fn main() {}").unwrap();
+
+        let changed = HashSet::new();
+        let kept = filter_changed(vec![file], workdir.path(), &changed);
+
+        assert!(kept.is_empty());
+    }
+
+    #[test]
+    fn keeps_changed_files_inside_workdir() {
+        let workdir = TempDir::new("keeps-changed");
+        let file = workdir.path().join("main.rs");
+        std::fs::write(&file, "fn main() {}").unwrap();
+
+        let mut changed = HashSet::new();
+        changed.insert(file.clone());
+        let kept = filter_changed(vec![file.clone()], workdir.path(), &changed);
+
+        assert_eq!(kept, vec![file]);
+    }
+}
@@ -0,0 +1,4 @@
+//! Output formats for check results.
+
+pub mod json;
+pub mod sarif;
@@ -4,21 +4,26 @@ use std::io::{self, Write};
 
 use loq_core::report::{FindingKind, Report};
 use loq_core::MatchBy;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize)]
 struct JsonOutput {
     version: &'static str,
     violations: Vec<JsonViolation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    suppressed: Vec<JsonViolation>,
     summary: JsonSummary,
 }
 
-#[derive(Debug, Serialize)]
-struct JsonViolation {
-    path: String,
-    lines: usize,
-    max_lines: usize,
-    rule: String,
+/// A single reported violation. Also reused as the baseline snapshot shape
+/// (see `crate::baseline`), since a baseline is just a prior check's
+/// violations keyed by path.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct JsonViolation {
+    pub(crate) path: String,
+    pub(crate) lines: usize,
+    pub(crate) max_lines: usize,
+    pub(crate) rule: String,
 }
 
 #[derive(Debug, Serialize)]
@@ -27,7 +32,14 @@ struct JsonSummary {
     violations: usize,
 }
 
-pub fn write_json<W: Write>(writer: &mut W, report: &Report) -> io::Result<()> {
+fn rule_name(matched_by: &MatchBy) -> String {
+    match matched_by {
+        MatchBy::Rule { pattern } => pattern.clone(),
+        MatchBy::Default => "default".to_string(),
+    }
+}
+
+pub fn write_json<W: Write>(writer: &mut W, report: &Report, verbose: bool) -> io::Result<()> {
     let violations = report
         .findings
         .iter()
@@ -39,15 +51,11 @@ pub fn write_json<W: Write>(writer: &mut W, report: &Report) -> io::Result<()> {
                 ..
             } = &finding.kind
             {
-                let rule = match matched_by {
-                    MatchBy::Rule { pattern } => pattern.clone(),
-                    MatchBy::Default => "default".to_string(),
-                };
                 Some(JsonViolation {
                     path: finding.path.clone(),
                     lines: *actual,
                     max_lines: *limit,
-                    rule,
+                    rule: rule_name(matched_by),
                 })
             } else {
                 None
@@ -55,9 +63,36 @@ pub fn write_json<W: Write>(writer: &mut W, report: &Report) -> io::Result<()> {
         })
         .collect();
 
+    let suppressed = if verbose {
+        report
+            .findings
+            .iter()
+            .filter_map(|finding| {
+                if let FindingKind::Suppressed {
+                    actual,
+                    limit,
+                    matched_by,
+                } = &finding.kind
+                {
+                    Some(JsonViolation {
+                        path: finding.path.clone(),
+                        lines: *actual,
+                        max_lines: *limit,
+                        rule: rule_name(matched_by),
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+
     let output = JsonOutput {
         version: env!("CARGO_PKG_VERSION"),
         violations,
+        suppressed,
         summary: JsonSummary {
             files_checked: report.summary.total,
             violations: report.summary.errors,
@@ -0,0 +1,274 @@
+//! SARIF 2.1.0 output format for check results.
+//!
+//! Lets fence plug into GitHub code scanning, editor problem-matchers, and
+//! other SARIF-aware aggregator tools without bespoke glue.
+
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+
+use loq_core::report::{FindingKind, Report};
+use loq_core::MatchBy;
+use serde::Serialize;
+
+const SARIF_SCHEMA: &str =
+    "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/master/Schemata/sarif-schema-2.1.0.json";
+
+#[derive(Debug, Serialize)]
+struct SarifLog {
+    version: &'static str,
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    runs: Vec<SarifRun>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifRun {
+    tool: SarifTool,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifTool {
+    driver: SarifDriver,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifDriver {
+    name: &'static str,
+    version: &'static str,
+    rules: Vec<SarifRule>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifRule {
+    id: String,
+    #[serde(rename = "shortDescription")]
+    short_description: SarifText,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifText {
+    text: String,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifResult {
+    #[serde(rename = "ruleId")]
+    rule_id: String,
+    level: &'static str,
+    message: SarifText,
+    locations: Vec<SarifLocation>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifLocation {
+    #[serde(rename = "physicalLocation")]
+    physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifPhysicalLocation {
+    #[serde(rename = "artifactLocation")]
+    artifact_location: SarifArtifactLocation,
+    region: SarifRegion,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifArtifactLocation {
+    uri: String,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifRegion {
+    #[serde(rename = "startLine")]
+    start_line: usize,
+    #[serde(rename = "endLine")]
+    end_line: usize,
+}
+
+fn rule_id(matched_by: &MatchBy) -> String {
+    match matched_by {
+        MatchBy::Rule { pattern } => pattern.clone(),
+        MatchBy::Default => "default".to_string(),
+    }
+}
+
+fn rule_description(id: &str) -> String {
+    if id == "default" {
+        "File exceeds the default line limit.".to_string()
+    } else {
+        format!("File matching `{id}` exceeds its configured line limit.")
+    }
+}
+
+fn normalize_uri(path: &str) -> String {
+    path.replace('\\', "/")
+}
+
+pub fn write_sarif<W: Write>(writer: &mut W, report: &Report, verbose: bool) -> io::Result<()> {
+    let mut rules: BTreeMap<String, SarifRule> = BTreeMap::new();
+    let mut results = Vec::new();
+
+    for finding in &report.findings {
+        let (actual, limit, matched_by, level) = match &finding.kind {
+            FindingKind::Violation {
+                actual,
+                limit,
+                matched_by,
+            } => (actual, limit, matched_by, "error"),
+            FindingKind::Suppressed {
+                actual,
+                limit,
+                matched_by,
+            } if verbose => (actual, limit, matched_by, "note"),
+            _ => continue,
+        };
+
+        let id = rule_id(matched_by);
+        rules.entry(id.clone()).or_insert_with(|| SarifRule {
+            id: id.clone(),
+            short_description: SarifText {
+                text: rule_description(&id),
+            },
+        });
+
+        results.push(SarifResult {
+            rule_id: id,
+            level,
+            message: SarifText {
+                text: format!("file has {actual} lines, limit is {limit}"),
+            },
+            locations: vec![SarifLocation {
+                physical_location: SarifPhysicalLocation {
+                    artifact_location: SarifArtifactLocation {
+                        uri: normalize_uri(&finding.path),
+                    },
+                    region: SarifRegion {
+                        start_line: limit + 1,
+                        end_line: *actual,
+                    },
+                },
+            }],
+        });
+    }
+
+    let log = SarifLog {
+        version: "2.1.0",
+        schema: SARIF_SCHEMA,
+        runs: vec![SarifRun {
+            tool: SarifTool {
+                driver: SarifDriver {
+                    name: "fence",
+                    version: env!("CARGO_PKG_VERSION"),
+                    rules: rules.into_values().collect(),
+                },
+            },
+            results,
+        }],
+    };
+
+    serde_json::to_writer_pretty(&mut *writer, &log)?;
+    writeln!(writer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use loq_core::report::{Finding, Summary};
+
+    #[test]
+    fn normalize_uri_converts_backslashes() {
+        assert_eq!(normalize_uri("src\\main.rs"), "src/main.rs");
+        assert_eq!(normalize_uri("src/main.rs"), "src/main.rs");
+    }
+
+    #[test]
+    fn region_covers_limit_plus_one_through_actual() {
+        let report = Report {
+            findings: vec![Finding {
+                path: "src/big.rs".to_string(),
+                kind: FindingKind::Violation {
+                    actual: 420,
+                    limit: 300,
+                    matched_by: MatchBy::Default,
+                },
+            }],
+            summary: Summary {
+                total: 1,
+                errors: 1,
+            },
+        };
+
+        let mut out = Vec::new();
+        write_sarif(&mut out, &report, false).unwrap();
+        let log: serde_json::Value = serde_json::from_slice(&out).unwrap();
+
+        let region = &log["runs"][0]["results"][0]["locations"][0]["physicalLocation"]["region"];
+        assert_eq!(region["startLine"], 301);
+        assert_eq!(region["endLine"], 420);
+    }
+
+    #[test]
+    fn suppressed_findings_only_appear_when_verbose() {
+        let report = Report {
+            findings: vec![Finding {
+                path: "src/generated.rs".to_string(),
+                kind: FindingKind::Suppressed {
+                    actual: 5000,
+                    limit: 300,
+                    matched_by: MatchBy::Default,
+                },
+            }],
+            summary: Summary {
+                total: 1,
+                errors: 0,
+            },
+        };
+
+        let mut quiet = Vec::new();
+        write_sarif(&mut quiet, &report, false).unwrap();
+        let quiet_log: serde_json::Value = serde_json::from_slice(&quiet).unwrap();
+        assert_eq!(quiet_log["runs"][0]["results"].as_array().unwrap().len(), 0);
+
+        let mut verbose = Vec::new();
+        write_sarif(&mut verbose, &report, true).unwrap();
+        let verbose_log: serde_json::Value = serde_json::from_slice(&verbose).unwrap();
+        assert_eq!(verbose_log["runs"][0]["results"][0]["level"], "note");
+    }
+
+    #[test]
+    fn distinct_rules_are_deduped() {
+        let report = Report {
+            findings: vec![
+                Finding {
+                    path: "a.rs".to_string(),
+                    kind: FindingKind::Violation {
+                        actual: 400,
+                        limit: 300,
+                        matched_by: MatchBy::Default,
+                    },
+                },
+                Finding {
+                    path: "b.rs".to_string(),
+                    kind: FindingKind::Violation {
+                        actual: 500,
+                        limit: 300,
+                        matched_by: MatchBy::Default,
+                    },
+                },
+            ],
+            summary: Summary {
+                total: 2,
+                errors: 2,
+            },
+        };
+
+        let mut out = Vec::new();
+        write_sarif(&mut out, &report, false).unwrap();
+        let log: serde_json::Value = serde_json::from_slice(&out).unwrap();
+
+        let rules = log["runs"][0]["tool"]["driver"]["rules"].as_array().unwrap();
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0]["id"], "default");
+    }
+}
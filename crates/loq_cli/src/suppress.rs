@@ -0,0 +1,54 @@
+//! Inline suppression pragmas.
+//!
+//! A file can opt out of the fence with a comment matching the configured
+//! ignore pattern (default: `fence:\s*ignore`, so both `fence:ignore` and
+//! `fence:ignore-file` trigger it), scanned during the same pass that counts
+//! lines.
+
+use regex::Regex;
+
+/// Whether any line in `contents` matches the ignore `pattern`.
+pub fn is_suppressed(contents: &str, pattern: &Regex) -> bool {
+    contents.lines().any(|line| pattern.is_match(line))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use loq_core::config::DEFAULT_IGNORE_PATTERN;
+
+    fn default_pattern() -> Regex {
+        Regex::new(DEFAULT_IGNORE_PATTERN).unwrap()
+    }
+
+    #[test]
+    fn matches_fence_ignore() {
+        let pattern = default_pattern();
+        assert!(is_suppressed("// fence:ignore\nfn main() {}", &pattern));
+    }
+
+    #[test]
+    fn matches_fence_ignore_file() {
+        let pattern = default_pattern();
+        assert!(is_suppressed("# fence:ignore-file\nprint(1)", &pattern));
+    }
+
+    #[test]
+    fn matches_with_whitespace_around_colon() {
+        let pattern = default_pattern();
+        assert!(is_suppressed("// fence: ignore", &pattern));
+    }
+
+    #[test]
+    fn no_match_without_pragma() {
+        let pattern = default_pattern();
+        assert!(!is_suppressed("fn main() {}\n// just a comment\n", &pattern));
+    }
+
+    #[test]
+    fn respects_custom_pattern() {
+        let pattern = Regex::new(r"do-not-lint").unwrap();
+        assert!(is_suppressed("// do-not-lint\n", &pattern));
+        assert!(!is_suppressed("// fence:ignore\n", &pattern));
+    }
+}
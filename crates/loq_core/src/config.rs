@@ -0,0 +1,37 @@
+//! `.fence.toml` configuration.
+
+use serde::{Deserialize, Serialize};
+
+/// Default regex used to detect inline suppression pragmas such as
+/// `fence:ignore` or `fence:ignore-file`.
+pub const DEFAULT_IGNORE_PATTERN: &str = r"fence:\s*ignore";
+
+/// Parsed `.fence.toml`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub default_limit: usize,
+    pub rules: Vec<RuleConfig>,
+    pub exemptions: Vec<String>,
+    /// Regex that, if it matches any line in a file, excuses that file from
+    /// its line limit.
+    pub ignore_pattern: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            default_limit: 300,
+            rules: Vec::new(),
+            exemptions: Vec::new(),
+            ignore_pattern: DEFAULT_IGNORE_PATTERN.to_string(),
+        }
+    }
+}
+
+/// A single glob -> limit rule.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuleConfig {
+    pub pattern: String,
+    pub limit: usize,
+}
@@ -0,0 +1,17 @@
+//! Core domain types shared by fence's CLI: line-count rules, check
+//! results, and config.
+
+pub mod config;
+pub mod report;
+
+pub use config::Config;
+pub use report::{Finding, FindingKind, Report, Summary};
+
+/// How a file's line-count rule was determined.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatchBy {
+    /// Matched an explicit glob rule in the config.
+    Rule { pattern: String },
+    /// Fell back to the configured default limit.
+    Default,
+}
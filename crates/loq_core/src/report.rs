@@ -0,0 +1,46 @@
+//! Check results: the findings produced by walking and evaluating files.
+
+use crate::MatchBy;
+
+/// The outcome of checking a set of files.
+#[derive(Debug, Default)]
+pub struct Report {
+    pub findings: Vec<Finding>,
+    pub summary: Summary,
+}
+
+/// A single file's check result.
+#[derive(Debug)]
+pub struct Finding {
+    pub path: String,
+    pub kind: FindingKind,
+}
+
+/// What happened when a file was checked.
+#[derive(Debug, Clone)]
+pub enum FindingKind {
+    /// File is within its line limit.
+    Ok { actual: usize, limit: usize },
+    /// File exceeds its line limit.
+    Violation {
+        actual: usize,
+        limit: usize,
+        matched_by: MatchBy,
+    },
+    /// File was skipped (e.g. binary, unreadable).
+    Skipped { reason: String },
+    /// File exceeds its line limit but carries an inline suppression
+    /// pragma, so it's excused rather than reported as an error.
+    Suppressed {
+        actual: usize,
+        limit: usize,
+        matched_by: MatchBy,
+    },
+}
+
+/// Aggregate counts across all findings.
+#[derive(Debug, Default)]
+pub struct Summary {
+    pub total: usize,
+    pub errors: usize,
+}